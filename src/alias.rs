@@ -0,0 +1,116 @@
+//! Weighted discrete sampling via Vose's alias method, the same approach
+//! the `rand` crate uses in `distributions::weighted::alias_method`.
+
+use alloc::vec::Vec;
+
+use crate::Ra;
+
+/// A precomputed table for sampling an index in `0..weights.len()`
+/// according to arbitrary non-negative weights, in O(1) per draw.
+pub struct AliasTable {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    /// Builds a table from a slice of non-negative weights.
+    ///
+    /// Panics if `weights` is empty or its weights don't sum to
+    /// something positive.
+    pub fn new(weights: &[f64]) -> Self {
+        let len = weights.len();
+        assert!(len > 0, "AliasTable needs at least one weight");
+
+        let sum: f64 = weights.iter().sum();
+        assert!(sum > 0.0, "AliasTable needs weights summing to more than 0");
+        let scale = len as f64 / sum;
+
+        let mut prob = alloc::vec![0.0; len];
+        let mut alias = alloc::vec![0; len];
+        let mut scaled: Vec<f64> = weights.iter().map(|w| w * scale).collect();
+
+        let mut small: Vec<usize> = Vec::new();
+        let mut large: Vec<usize> = Vec::new();
+        for (i, &w) in scaled.iter().enumerate() {
+            if w < 1.0 {
+                small.push(i);
+            } else {
+                large.push(i);
+            }
+        }
+
+        while !small.is_empty() && !large.is_empty() {
+            let s = small.pop().unwrap();
+            let l = large.pop().unwrap();
+
+            prob[s] = scaled[s];
+            alias[s] = l;
+
+            scaled[l] = (scaled[l] + scaled[s]) - 1.0;
+            if scaled[l] < 1.0 {
+                small.push(l);
+            } else {
+                large.push(l);
+            }
+        }
+
+        for i in large {
+            prob[i] = 1.0;
+        }
+        for i in small {
+            prob[i] = 1.0;
+        }
+
+        Self { prob, alias }
+    }
+
+    /// Draws an index in `0..len`, biased by the weights this table was
+    /// built from.
+    pub fn sample(&self, ra: &mut Ra) -> usize {
+        let i = ra.range(0_usize, self.prob.len());
+        let f = ra.sample::<f64>();
+        if f < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AliasTable;
+    use crate::Ra;
+
+    #[test]
+    fn always_samples_a_valid_index() {
+        let table = AliasTable::new(&[1.0, 0.0, 3.0, 6.0]);
+        let mut ra = Ra::default();
+        for _ in 0..10000 {
+            assert!(table.sample(&mut ra) < 4);
+        }
+    }
+
+    #[test]
+    fn favors_heavier_weights() {
+        let table = AliasTable::new(&[1.0, 9.0]);
+        let mut ra = Ra::default();
+        let mut counts = [0_u32; 2];
+        for _ in 0..10000 {
+            counts[table.sample(&mut ra)] += 1;
+        }
+        assert!(counts[1] > counts[0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_empty_weights() {
+        AliasTable::new(&[]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_all_zero_weights() {
+        AliasTable::new(&[0.0, 0.0, 0.0]);
+    }
+}
@@ -23,6 +23,21 @@
 //!
 //! NOTE: This create is not at all suitable for cryptographic use.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+mod alias;
+#[cfg(feature = "alloc")]
+pub use alias::AliasTable;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+mod normal;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use normal::Normal;
+
 /// The struct that holds all the random state. Can be instanced
 /// as many times as you want!
 #[derive(Copy, Clone, Debug)]
@@ -79,6 +94,23 @@ impl Ra {
     pub fn sample<T: Sample>(&mut self) -> T {
         T::sample(self)
     }
+
+    /// Fills `dest` with random bytes, one `xorwow()` draw per 8 bytes.
+    ///
+    /// Faster than looping `sample::<u8>()`, which throws away 56 of
+    /// every 64 random bits.
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.xorwow().to_le_bytes());
+        }
+
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            let bytes = self.xorwow().to_le_bytes();
+            remainder.copy_from_slice(&bytes[..remainder.len()]);
+        }
+    }
 }
 
 // Boring boilerplate bellow here!
@@ -130,6 +162,108 @@ impl_sample!(large i128);
 impl_sample!(float f32);
 impl_sample!(float f64);
 
+/// How to draw a uniform value in `[low, high)` from random [u64]s,
+/// without the modulo bias you'd get from `xorwow() % n`.
+pub trait SampleRange: Sized {
+    fn sample_range(ra: &mut Ra, low: Self, high: Self) -> Self;
+}
+
+macro_rules! impl_sample_range {
+    ( $ty:tt ) => {
+        impl SampleRange for $ty {
+            fn sample_range(ra: &mut Ra, low: Self, high: Self) -> Self {
+                assert!(low < high, "range requires low < high");
+
+                // Lemire's algorithm: a 64x64->128 bit multiply-shift with
+                // rejection, so every value in the range is equally likely.
+                // The modulo below is only needed on the rare rejection
+                // path, not on every draw.
+                let n = (high - low) as u64;
+                let x = ra.xorwow();
+                let mut m = (x as u128) * (n as u128);
+                let mut low_bits = m as u64;
+                if low_bits < n {
+                    let threshold = n.wrapping_neg() % n;
+                    while low_bits < threshold {
+                        let x = ra.xorwow();
+                        m = (x as u128) * (n as u128);
+                        low_bits = m as u64;
+                    }
+                }
+                low + ((m >> 64) as u64) as Self
+            }
+        }
+    };
+}
+
+impl_sample_range!(u8);
+impl_sample_range!(u16);
+impl_sample_range!(u32);
+impl_sample_range!(u64);
+impl_sample_range!(usize);
+
+/// A weighted coin flip, for when a plain `bool` sample's ~50/50 split
+/// isn't what you want.
+///
+/// Stores the probability as a fixed-point threshold, so sampling is a
+/// single comparison with no floating-point work per draw.
+#[derive(Copy, Clone, Debug)]
+pub struct Bernoulli {
+    threshold: u64,
+    always_true: bool,
+}
+
+impl Bernoulli {
+    /// Builds a `Bernoulli` that samples `true` with probability `p`.
+    ///
+    /// Panics if `p` isn't in `[0.0, 1.0]`.
+    pub fn new(p: f64) -> Self {
+        assert!((0.0..=1.0).contains(&p), "p must be in [0.0, 1.0]");
+        // 2^64 doesn't fit in a u64, so p == 1.0 gets its own flag
+        // instead of a threshold.
+        if p == 1.0 {
+            Self { threshold: 0, always_true: true }
+        } else {
+            Self { threshold: (p * (u64::MAX as f64 + 1.0)) as u64, always_true: false }
+        }
+    }
+
+    /// Draws a weighted boolean.
+    pub fn sample(&self, ra: &mut Ra) -> bool {
+        self.always_true || ra.xorwow() < self.threshold
+    }
+}
+
+impl Ra {
+    /// Draws a uniform value in `[low, high)`, with no modulo bias.
+    ///
+    /// Panics if `low >= high`, same as an empty range would.
+    pub fn range<T: SampleRange>(&mut self, low: T, high: T) -> T {
+        T::sample_range(self, low, high)
+    }
+
+    /// Shuffles a slice in place, with every permutation equally likely.
+    ///
+    /// Implemented as the modern Fisher–Yates shuffle.
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        let len = slice.len();
+        for i in (1..len).rev() {
+            let j = self.range(0_usize, i + 1);
+            slice.swap(i, j);
+        }
+    }
+
+    /// Picks a uniformly random element from a slice, or `None` if it's
+    /// empty.
+    pub fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        if slice.is_empty() {
+            return None;
+        }
+        let i = self.range(0_usize, slice.len());
+        slice.get(i)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -145,6 +279,16 @@ mod tests {
         assert_ne!(ra.sample::<u64>(), ra.sample::<u64>());
     }
 
+    #[test]
+    fn fill_bytes_fills_the_whole_buffer() {
+        let mut ra = Ra::new(seed());
+        let mut buf = [0_u8; 19];
+        ra.fill_bytes(&mut buf);
+        assert_ne!(buf, [0_u8; 19]);
+        // The last 3 bytes come from the trailing partial chunk.
+        assert_ne!(buf[16..], [0_u8; 3]);
+    }
+
     #[test]
     fn negative_random() {
         let mut ra = Ra::new(seed());
@@ -217,4 +361,88 @@ mod tests {
             assert!(*v > (MEAN - VAR.sqrt() * 5.0) as u64);
         }
     }
+
+    #[test]
+    fn range_stays_in_bounds() {
+        let mut ra = Ra::new(seed());
+        for _ in 0..1000000 {
+            let sample = ra.range(10_u32, 20_u32);
+            assert!(sample >= 10 && sample < 20);
+        }
+    }
+
+    #[test]
+    fn range_covers_the_whole_span() {
+        let mut ra = Ra::new(seed());
+        let mut seen = [false; 5];
+        for _ in 0..1000 {
+            seen[ra.range(0_u8, 5_u8) as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    #[should_panic]
+    fn range_panics_when_low_is_not_less_than_high() {
+        let mut ra = Ra::new(seed());
+        ra.range(20_u32, 10_u32);
+    }
+
+    #[test]
+    fn shuffle_keeps_all_elements() {
+        let mut ra = Ra::new(seed());
+        let mut slice: [u32; 6] = [0, 1, 2, 3, 4, 5];
+        ra.shuffle(&mut slice);
+        let mut sorted = slice;
+        sorted.sort();
+        assert_eq!(sorted, [0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn choose_picks_a_member_of_the_slice() {
+        let mut ra = Ra::new(seed());
+        let slice = [10, 20, 30];
+        for _ in 0..1000 {
+            assert!(slice.contains(ra.choose(&slice).unwrap()));
+        }
+    }
+
+    #[test]
+    fn choose_on_empty_slice_is_none() {
+        let mut ra = Ra::new(seed());
+        let slice: [u32; 0] = [];
+        assert_eq!(ra.choose(&slice), None);
+    }
+
+    #[test]
+    fn bernoulli_always_true_or_false_at_the_extremes() {
+        let mut ra = Ra::new(seed());
+        let always = crate::Bernoulli::new(1.0);
+        let never = crate::Bernoulli::new(0.0);
+        for _ in 0..1000 {
+            assert!(always.sample(&mut ra));
+            assert!(!never.sample(&mut ra));
+        }
+    }
+
+    #[test]
+    fn bernoulli_matches_its_probability() {
+        let mut ra = Ra::new(seed());
+        let coin = crate::Bernoulli::new(0.25);
+        const NUM_SAMPLES: u32 = 1000000;
+        let mut count = 0;
+        for _ in 0..NUM_SAMPLES {
+            if coin.sample(&mut ra) {
+                count += 1;
+            }
+        }
+        let p = count as f64 / NUM_SAMPLES as f64;
+        assert!((p - 0.25).abs() < 0.01);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bernoulli_panics_on_probability_out_of_range() {
+        crate::Bernoulli::new(1.5);
+    }
 }
@@ -0,0 +1,79 @@
+//! Normally-distributed samples via the Marsaglia polar method. Needs
+//! `sqrt`/`ln`, which `no_std` doesn't have on its own, so this module
+//! is gated behind the `std` or `libm` feature.
+
+use crate::Ra;
+
+#[cfg(feature = "std")]
+fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+#[cfg(feature = "std")]
+fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+#[cfg(all(feature = "libm", not(feature = "std")))]
+fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+#[cfg(all(feature = "libm", not(feature = "std")))]
+fn ln(x: f64) -> f64 {
+    libm::log(x)
+}
+
+/// A Gaussian distribution with the given `mean` and `std_dev`.
+///
+/// The Marsaglia polar method turns each accepted pair of uniforms into
+/// two independent normal values, so a `Normal` caches the spare one and
+/// returns it on the next call instead of throwing it away.
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+    cached: Option<f64>,
+}
+
+impl Normal {
+    pub fn new(mean: f64, std_dev: f64) -> Self {
+        Self { mean, std_dev, cached: None }
+    }
+
+    /// Draws a sample from this distribution.
+    pub fn sample(&mut self, ra: &mut Ra) -> f64 {
+        if let Some(cached) = self.cached.take() {
+            return self.mean + self.std_dev * cached;
+        }
+
+        loop {
+            let u = 2.0 * ra.sample::<f64>() - 1.0;
+            let v = 2.0 * ra.sample::<f64>() - 1.0;
+            let s = u * u + v * v;
+            if s >= 1.0 || s == 0.0 {
+                continue;
+            }
+
+            let factor = sqrt(-2.0 * ln(s) / s);
+            self.cached = Some(v * factor);
+            return self.mean + self.std_dev * u * factor;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Normal;
+    use crate::Ra;
+
+    #[test]
+    fn mean_of_many_samples_is_close_to_the_mean() {
+        let mut ra = Ra::default();
+        let mut normal = Normal::new(5.0, 2.0);
+        const NUM_SAMPLES: u32 = 1000000;
+        let mut sum = 0.0;
+        for _ in 0..NUM_SAMPLES {
+            sum += normal.sample(&mut ra);
+        }
+        let mean = sum / NUM_SAMPLES as f64;
+        assert!((mean - 5.0).abs() < 0.05);
+    }
+}